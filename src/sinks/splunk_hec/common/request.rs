@@ -0,0 +1,82 @@
+use bytes::{Bytes, BytesMut};
+use futures_util::{stream::BoxStream, StreamExt};
+use vector_common::request_metadata::{MetaDescriptive, RequestMetadata};
+use vector_core::event::{EventFinalizers, Finalizable};
+
+/// The body of a request sent to a Splunk HEC endpoint.
+///
+/// Most batches are small enough that building the whole JSON payload up front is cheap, but a
+/// sink configured with a large batch size can end up holding megabytes of already-serialized
+/// JSON in memory for the lifetime of the request. [`RequestBody::Streamed`] defers serialization
+/// of each event until the HTTP client actually polls the body for bytes, so peak memory is
+/// bounded by one event's encoding rather than the whole batch's; the HTTP client sends it with a
+/// chunked transfer encoding, since the total length isn't known up front.
+pub enum RequestBody {
+    /// The request body has already been fully serialized into a single buffer.
+    Buffered(Bytes),
+    /// The request body is serialized lazily, one already-delimited JSON event at a time, as the
+    /// HTTP client polls for bytes.
+    Streamed(BoxStream<'static, Bytes>),
+}
+
+impl RequestBody {
+    /// Drains the body into a single buffer, serializing it now if it's a [`RequestBody::Streamed`].
+    ///
+    /// Used by call sites (e.g. building a retry) that need the whole body available
+    /// synchronously rather than as a stream.
+    pub async fn into_bytes(self) -> Bytes {
+        match self {
+            RequestBody::Buffered(bytes) => bytes,
+            RequestBody::Streamed(mut stream) => {
+                let mut out = BytesMut::new();
+                while let Some(chunk) = stream.next().await {
+                    out.extend_from_slice(&chunk);
+                }
+                out.freeze()
+            }
+        }
+    }
+}
+
+/// A request to a Splunk HEC endpoint, shared by the `logs` and `metrics` sinks.
+pub struct HecRequest {
+    pub body: RequestBody,
+    pub metadata: RequestMetadata,
+    pub finalizers: EventFinalizers,
+}
+
+impl Finalizable for HecRequest {
+    fn take_finalizers(&mut self) -> EventFinalizers {
+        std::mem::take(&mut self.finalizers)
+    }
+}
+
+impl MetaDescriptive for HecRequest {
+    fn get_metadata(&self) -> &RequestMetadata {
+        &self.metadata
+    }
+
+    fn metadata_mut(&mut self) -> &mut RequestMetadata {
+        &mut self.metadata
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::stream;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn into_bytes_returns_a_buffered_body_unchanged() {
+        let body = RequestBody::Buffered(Bytes::from_static(b"hello"));
+        assert_eq!(body.into_bytes().await, Bytes::from_static(b"hello"));
+    }
+
+    #[tokio::test]
+    async fn into_bytes_drains_a_streamed_body_in_order() {
+        let chunks = vec![Bytes::from_static(b"foo"), Bytes::from_static(b"bar")];
+        let body = RequestBody::Streamed(stream::iter(chunks).boxed());
+        assert_eq!(body.into_bytes().await, Bytes::from_static(b"foobar"));
+    }
+}
@@ -0,0 +1,181 @@
+use std::{io::Write, sync::Arc};
+
+use bytes::Bytes;
+use futures_util::{stream, stream::BoxStream, StreamExt};
+use serde_json::{json, Map, Value};
+use vector_common::request_metadata::{RequestMetadata, RequestMetadataBuilder};
+use vector_core::{
+    event::{EventFinalizers, Finalizable},
+    ByteSizeOf,
+};
+
+use super::sink::{HecMetricsProcessedEventMetadata, HecProcessedEvent};
+use crate::sinks::{
+    splunk_hec::common::request::{HecRequest, RequestBody},
+    util::{builder::RequestBuilder, Compression, Compressor},
+};
+
+/// Builds the HTTP requests sent to a Splunk HEC metrics endpoint from batches of
+/// [`HecProcessedEvent`]s.
+#[derive(Clone)]
+pub struct HecMetricsRequestBuilder {
+    pub compression: Compression,
+    /// Whether the request body is serialized lazily into a `Stream<Item = Bytes>` instead of
+    /// being fully buffered up front. See [`RequestBody`] for what each option does.
+    streaming_body: bool,
+}
+
+impl HecMetricsRequestBuilder {
+    pub const fn new(compression: Compression) -> Self {
+        Self {
+            compression,
+            streaming_body: false,
+        }
+    }
+
+    /// Returns `self` with `streaming_body` set, selecting whether batches are serialized lazily
+    /// rather than fully buffered up front.
+    pub const fn with_streaming_body(mut self, streaming_body: bool) -> Self {
+        self.streaming_body = streaming_body;
+        self
+    }
+}
+
+/// Serializes one event's metadata into a single HEC multi-metric JSON event.
+///
+/// Splunk's multi-metric HEC format expects every metric name/value pair as its own `fields`
+/// entry, keyed as `metric_name:<name>`, alongside an `event: "metric"` marker -- a
+/// `fields: [[name, value], ...]` array isn't recognized as metrics at all. A
+/// [`HecMetricsProcessedEventMetadata`] can carry more than one pair (aggregated histograms,
+/// aggregated summaries, and distributions expand into one pair per bucket/quantile plus
+/// `.sum`/`.count`), so every one of them gets flattened into its own `metric_name:` entry in
+/// the same `fields` object rather than nested.
+fn encode_event(event: HecProcessedEvent) -> Value {
+    let HecProcessedEvent { metadata, .. } = event;
+    let HecMetricsProcessedEventMetadata {
+        sourcetype,
+        source,
+        index,
+        host,
+        fields,
+        ..
+    } = metadata;
+
+    let mut hec_fields = Map::with_capacity(fields.len());
+    for (name, value) in fields {
+        hec_fields.insert(format!("metric_name:{name}"), json!(value));
+    }
+
+    let mut hec_event = Map::new();
+    hec_event.insert("event".to_owned(), json!("metric"));
+    hec_event.insert("fields".to_owned(), Value::Object(hec_fields));
+    if let Some(sourcetype) = sourcetype {
+        hec_event.insert("sourcetype".to_owned(), json!(sourcetype));
+    }
+    if let Some(source) = source {
+        hec_event.insert("source".to_owned(), json!(source));
+    }
+    if let Some(index) = index {
+        hec_event.insert("index".to_owned(), json!(index));
+    }
+    if let Some(host) = host {
+        hec_event.insert("host".to_owned(), json!(host));
+    }
+
+    Value::Object(hec_event)
+}
+
+/// Encodes one event to its newline-delimited JSON wire representation.
+fn encode_event_to_bytes(event: HecProcessedEvent) -> Bytes {
+    let mut json = encode_event(event).to_string();
+    json.push('\n');
+    Bytes::from(json)
+}
+
+/// Compresses `bytes` with `compression`, matching whatever `Content-Encoding` the service
+/// advertises for this sink.
+///
+/// Writing to and finishing an in-memory [`Compressor`] can't fail, so this never returns an
+/// error to its callers.
+fn compress(compression: Compression, bytes: &[u8]) -> Bytes {
+    let mut compressor = Compressor::from(compression);
+    compressor
+        .write_all(bytes)
+        .expect("in-memory compressor write is infallible");
+    Bytes::from(
+        compressor
+            .finish()
+            .expect("in-memory compressor finish is infallible"),
+    )
+}
+
+impl RequestBuilder<(Option<Arc<str>>, Vec<HecProcessedEvent>)> for HecMetricsRequestBuilder {
+    type Metadata = EventFinalizers;
+    type Events = Vec<HecProcessedEvent>;
+    type Payload = RequestBody;
+    type Request = HecRequest;
+    type Error = std::io::Error;
+
+    fn compression(&self) -> Compression {
+        self.compression
+    }
+
+    fn split_input(
+        &self,
+        input: (Option<Arc<str>>, Vec<HecProcessedEvent>),
+    ) -> (Self::Metadata, RequestMetadataBuilder, Self::Events) {
+        let (_token, mut events) = input;
+        let finalizers = events.take_finalizers();
+        let request_metadata_builder = RequestMetadataBuilder::from_events(&events);
+        (finalizers, request_metadata_builder, events)
+    }
+
+    fn encode_events(&self, events: Self::Events) -> Result<Self::Payload, Self::Error> {
+        let compression = self.compression;
+        let body = if self.streaming_body {
+            // Each event is compressed independently as soon as it's serialized, rather than
+            // the whole batch being compressed together once fully buffered, so peak memory for
+            // a large batch stays bounded by one event's encoding instead of the whole batch's.
+            // gzip and zstd both decode a concatenation of independently-compressed frames the
+            // same as if the uncompressed content had been concatenated first and compressed
+            // once, so the receiving end sees exactly the same bytes either way.
+            let stream: BoxStream<'static, Bytes> = stream::iter(events)
+                .map(move |event| compress(compression, &encode_event_to_bytes(event)))
+                .boxed();
+            RequestBody::Streamed(stream)
+        } else {
+            let mut buffer = Vec::with_capacity(events.size_of());
+            for event in events {
+                buffer.extend_from_slice(&encode_event_to_bytes(event));
+            }
+            RequestBody::Buffered(compress(compression, &buffer))
+        };
+
+        Ok(body)
+    }
+
+    fn build_request(
+        &self,
+        finalizers: Self::Metadata,
+        request_metadata: RequestMetadata,
+        payload: Self::Payload,
+    ) -> Self::Request {
+        HecRequest {
+            body: payload,
+            metadata: request_metadata,
+            finalizers,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compress_with_no_compression_returns_the_input_unchanged() {
+        let input = b"hello world";
+        let output = compress(Compression::None, input);
+        assert_eq!(&output[..], input);
+    }
+}
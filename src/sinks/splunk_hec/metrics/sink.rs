@@ -34,6 +34,13 @@ pub struct HecMetricsSink<S> {
     pub index: Option<Template>,
     pub host: String,
     pub default_namespace: Option<String>,
+    /// Whether batched requests are serialized lazily into a chunked, streamed HTTP body rather
+    /// than being fully buffered in memory up front.
+    ///
+    /// This trades a small amount of per-request overhead for bounded peak memory when
+    /// `builder_limit`/batch sizes are large. Defaults to `false` to preserve the existing
+    /// buffered behavior.
+    pub streaming_body: bool,
 }
 
 impl<S> HecMetricsSink<S>
@@ -65,7 +72,15 @@ where
                 ))
             })
             .batched_partitioned(EventPartitioner::default(), self.batch_settings)
-            .request_builder(builder_limit, self.request_builder)
+            // `with_streaming_body` selects whether each batch's `HecRequest` body is
+            // serialized lazily into a `Stream<Item = Bytes>` and sent chunked, or fully
+            // buffered up front as today; the actual encoding lives on the request builder
+            // since it's the one assembling the request body.
+            .request_builder(
+                builder_limit,
+                self.request_builder
+                    .with_streaming_body(self.streaming_body),
+            )
             .filter_map(|request| async move {
                 match request {
                     Err(e) => {
@@ -114,7 +129,16 @@ pub struct HecMetricsProcessedEventMetadata {
     pub index: Option<String>,
     pub host: Option<String>,
     pub metric_name: String,
-    pub metric_value: f64,
+    /// The `(field name, value)` pairs to emit for this metric.
+    ///
+    /// Counters and gauges produce a single pair holding the metric's own name and value.
+    /// Aggregated histograms, aggregated summaries, and distributions can't be represented as
+    /// one scalar, so they expand into several pairs instead (per-bucket counts keyed by
+    /// `<metric_name>.bucket.le_<upper_limit>`, per-quantile values keyed by
+    /// `<metric_name>.quantile.<quantile>`, per-sample value/rate pairs keyed by
+    /// `<metric_name>.sample.<index>.value`/`.rate`, plus `<metric_name>.sum` and
+    /// `<metric_name>.count`).
+    pub fields: Vec<(String, f64)>,
     pub templated_field_keys: Vec<String>,
 }
 
@@ -125,6 +149,11 @@ impl ByteSizeOf for HecMetricsProcessedEventMetadata {
             + self.index.allocated_bytes()
             + self.host.allocated_bytes()
             + self.metric_name.allocated_bytes()
+            + self
+                .fields
+                .iter()
+                .map(|(name, _)| name.allocated_bytes())
+                .sum::<usize>()
             + self.templated_field_keys.allocated_bytes()
     }
 }
@@ -134,10 +163,60 @@ impl HecMetricsProcessedEventMetadata {
         encode_namespace(metric.namespace().or(default_namespace), '.', metric.name())
     }
 
-    fn extract_metric_value(metric: &Metric) -> Option<f64> {
-        match *metric.value() {
-            MetricValue::Counter { value } => Some(value),
-            MetricValue::Gauge { value } => Some(value),
+    fn extract_metric_fields(metric: &Metric, metric_name: &str) -> Option<Vec<(String, f64)>> {
+        match metric.value() {
+            MetricValue::Counter { value } => Some(vec![(metric_name.to_owned(), *value)]),
+            MetricValue::Gauge { value } => Some(vec![(metric_name.to_owned(), *value)]),
+            MetricValue::AggregatedHistogram {
+                buckets,
+                count,
+                sum,
+            } => {
+                let mut fields: Vec<(String, f64)> = buckets
+                    .iter()
+                    .map(|bucket| {
+                        (
+                            format!("{metric_name}.bucket.le_{}", bucket.upper_limit),
+                            bucket.count as f64,
+                        )
+                    })
+                    .collect();
+                fields.push((format!("{metric_name}.sum"), *sum));
+                fields.push((format!("{metric_name}.count"), *count as f64));
+                Some(fields)
+            }
+            MetricValue::AggregatedSummary {
+                quantiles,
+                count,
+                sum,
+            } => {
+                let mut fields: Vec<(String, f64)> = quantiles
+                    .iter()
+                    .map(|quantile| {
+                        (
+                            format!("{metric_name}.quantile.{}", quantile.quantile),
+                            quantile.value,
+                        )
+                    })
+                    .collect();
+                fields.push((format!("{metric_name}.sum"), *sum));
+                fields.push((format!("{metric_name}.count"), *count as f64));
+                Some(fields)
+            }
+            MetricValue::Distribution { samples, .. } => {
+                let mut fields: Vec<(String, f64)> = Vec::with_capacity(samples.len() * 2 + 2);
+                let mut count = 0.0;
+                let mut sum = 0.0;
+                for (i, sample) in samples.iter().enumerate() {
+                    fields.push((format!("{metric_name}.sample.{i}.value"), sample.value));
+                    fields.push((format!("{metric_name}.sample.{i}.rate"), sample.rate as f64));
+                    count += sample.rate as f64;
+                    sum += sample.value * sample.rate as f64;
+                }
+                fields.push((format!("{metric_name}.sum"), sum));
+                fields.push((format!("{metric_name}.count"), count));
+                Some(fields)
+            }
             _ => {
                 emit!(SplunkInvalidMetricReceivedError {
                     value: metric.value(),
@@ -170,7 +249,7 @@ pub fn process_metric(
         .collect::<Vec<_>>();
     let metric_name =
         HecMetricsProcessedEventMetadata::extract_metric_name(&metric, default_namespace);
-    let metric_value = HecMetricsProcessedEventMetadata::extract_metric_value(&metric)?;
+    let fields = HecMetricsProcessedEventMetadata::extract_metric_fields(&metric, &metric_name)?;
 
     let sourcetype =
         sourcetype.and_then(|sourcetype| render_template_string(sourcetype, &metric, "sourcetype"));
@@ -185,7 +264,7 @@ pub fn process_metric(
         index,
         host,
         metric_name,
-        metric_value,
+        fields,
         templated_field_keys,
     };
 
@@ -201,3 +280,57 @@ impl EventCount for HecProcessedEvent {
         1
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use vector_core::event::metric::{Sample, StatisticKind};
+
+    use super::*;
+
+    #[test]
+    fn extract_metric_fields_counter_emits_a_single_pair() {
+        let metric = Metric::new(
+            "requests",
+            vector_core::event::MetricKind::Absolute,
+            MetricValue::Counter { value: 3.0 },
+        );
+        let fields =
+            HecMetricsProcessedEventMetadata::extract_metric_fields(&metric, "requests").unwrap();
+        assert_eq!(fields, vec![("requests".to_owned(), 3.0)]);
+    }
+
+    #[test]
+    fn extract_metric_fields_distribution_expands_every_sample() {
+        let metric = Metric::new(
+            "latency",
+            vector_core::event::MetricKind::Absolute,
+            MetricValue::Distribution {
+                samples: vec![
+                    Sample {
+                        value: 1.0,
+                        rate: 2,
+                    },
+                    Sample {
+                        value: 3.0,
+                        rate: 1,
+                    },
+                ],
+                statistic: StatisticKind::Histogram,
+            },
+        );
+        let fields =
+            HecMetricsProcessedEventMetadata::extract_metric_fields(&metric, "latency").unwrap();
+
+        assert_eq!(
+            fields,
+            vec![
+                ("latency.sample.0.value".to_owned(), 1.0),
+                ("latency.sample.0.rate".to_owned(), 2.0),
+                ("latency.sample.1.value".to_owned(), 3.0),
+                ("latency.sample.1.rate".to_owned(), 1.0),
+                ("latency.sum".to_owned(), 5.0),
+                ("latency.count".to_owned(), 3.0),
+            ]
+        );
+    }
+}
@@ -1,4 +1,12 @@
-use bytes::BytesMut;
+use std::{
+    collections::{HashMap, VecDeque},
+    net::SocketAddr,
+    num::NonZeroUsize,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use bytes::{Bytes, BytesMut};
 use chrono::Utc;
 use codecs::{
     decoding::{DeserializerConfig, FramingConfig},
@@ -7,8 +15,12 @@ use codecs::{
 use futures::StreamExt;
 use listenfd::ListenFd;
 use lookup::{lookup_v2::BorrowedSegment, path};
+use socket2::{Domain, Protocol as SocketProtocol, Socket, Type};
+use tokio::sync::Notify;
 use tokio_util::codec::FramedRead;
-use vector_common::internal_event::{ByteSize, BytesReceived, InternalEventHandle as _, Protocol};
+use vector_common::internal_event::{
+    ByteSize, BytesReceived, InternalEventHandle as _, Protocol, Registered,
+};
 use vector_config::{configurable_component, NamedComponent};
 use vector_core::{
     config::{LegacyKey, LogNamespace},
@@ -21,6 +33,7 @@ use crate::{
     event::Event,
     internal_events::{
         SocketBindError, SocketEventsReceived, SocketMode, SocketReceiveError, StreamClosedError,
+        UdpEventsDropped, UdpInFlightQueueDepth, UdpInFlightQueueDepthUpdate,
     },
     serde::{default_decoding, default_framing_message_based},
     shutdown::ShutdownSignal,
@@ -32,6 +45,500 @@ use crate::{
     udp, SourceSender,
 };
 
+/// The default maximum number of decoded event batches allowed to be
+/// in flight between the receive loop and the send task.
+const fn default_max_in_flight_events() -> usize {
+    128
+}
+
+/// The default number of `SO_REUSEPORT` worker sockets bound per address.
+fn default_socket_workers() -> NonZeroUsize {
+    NonZeroUsize::new(1).expect("1 is non-zero")
+}
+
+/// The default amount of time to wait for room in the in-flight queue
+/// when `overload_strategy` is set to `block`.
+const fn default_send_timeout_secs() -> f64 {
+    1.0
+}
+
+/// Overload protection strategy applied once `max_in_flight_events` batches
+/// are already queued for the send task.
+#[configurable_component]
+#[derive(Clone, Copy, Debug)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum UdpOverloadStrategy {
+    /// Drop the incoming batch of events rather than queueing it.
+    DropNewest,
+
+    /// Drop the oldest queued batch of events to make room for the incoming one.
+    DropOldest,
+
+    /// Block the receive loop, waiting up to `timeout_secs` for room in the
+    /// queue before dropping the incoming batch.
+    Block {
+        /// The maximum amount of time, in seconds, to wait for room in the queue.
+        #[serde(default = "default_send_timeout_secs")]
+        timeout_secs: f64,
+    },
+}
+
+impl Default for UdpOverloadStrategy {
+    fn default() -> Self {
+        Self::DropNewest
+    }
+}
+
+/// A bounded FIFO queue of pending event batches shared between the UDP
+/// receive loop and the task that sends events downstream.
+///
+/// This decouples `recv_from` from `out.send_batch`, so a backpressured
+/// downstream no longer stalls the receive loop. Unlike a plain
+/// `tokio::sync::mpsc` channel, the producer can evict the oldest queued
+/// batch, which `mpsc::Sender` doesn't expose, in order to support
+/// [`UdpOverloadStrategy::DropOldest`].
+struct InFlightQueue {
+    inner: Mutex<VecDeque<Vec<Event>>>,
+    capacity: usize,
+    item_available: Notify,
+    space_available: Notify,
+    /// The largest depth this queue has reached, so a saturated source can be spotted even if
+    /// it has since drained back down between two metric scrapes.
+    high_water_mark: std::sync::atomic::AtomicUsize,
+    /// Registered once per source instance so the emitted metric is tagged to this `socket`
+    /// component, like the file's registered `BytesReceived` handles, rather than using the
+    /// untagged `metrics::gauge!` macro (which collides across instances).
+    depth_metric: Registered<UdpInFlightQueueDepth>,
+}
+
+impl InFlightQueue {
+    fn new(capacity: usize) -> Arc<Self> {
+        Arc::new(Self {
+            inner: Mutex::new(VecDeque::with_capacity(capacity)),
+            capacity,
+            item_available: Notify::new(),
+            space_available: Notify::new(),
+            high_water_mark: std::sync::atomic::AtomicUsize::new(0),
+            depth_metric: register!(UdpInFlightQueueDepth),
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.inner.lock().expect("in-flight queue poisoned").len()
+    }
+
+    fn update_gauge(&self) {
+        let depth = self.len();
+        let high_water_mark = self
+            .high_water_mark
+            .fetch_max(depth, std::sync::atomic::Ordering::Relaxed)
+            .max(depth);
+        self.depth_metric.emit(UdpInFlightQueueDepthUpdate {
+            depth,
+            high_water_mark,
+        });
+    }
+
+    /// Pushes `events` onto the queue, dropping it instead if the queue is full.
+    ///
+    /// Returns the dropped batch, if any.
+    fn push_drop_newest(&self, events: Vec<Event>) -> Option<Vec<Event>> {
+        let dropped = {
+            let mut queue = self.inner.lock().expect("in-flight queue poisoned");
+            if queue.len() >= self.capacity {
+                Some(events)
+            } else {
+                queue.push_back(events);
+                None
+            }
+        };
+        if dropped.is_none() {
+            self.item_available.notify_one();
+        }
+        self.update_gauge();
+        dropped
+    }
+
+    /// Pushes `events` onto the queue, evicting the oldest queued batch if the
+    /// queue is full to make room.
+    ///
+    /// Returns the evicted batch, if any.
+    fn push_drop_oldest(&self, events: Vec<Event>) -> Option<Vec<Event>> {
+        let dropped = {
+            let mut queue = self.inner.lock().expect("in-flight queue poisoned");
+            let dropped = if queue.len() >= self.capacity {
+                queue.pop_front()
+            } else {
+                None
+            };
+            queue.push_back(events);
+            dropped
+        };
+        self.item_available.notify_one();
+        self.update_gauge();
+        dropped
+    }
+
+    /// Pushes `events` onto the queue, waiting up to `timeout` for room if
+    /// the queue is full.
+    ///
+    /// Returns the batch back, undelivered, if `timeout` elapses first.
+    async fn push_block(&self, events: Vec<Event>, timeout: Duration) -> Result<(), Vec<Event>> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            {
+                let mut queue = self.inner.lock().expect("in-flight queue poisoned");
+                if queue.len() < self.capacity {
+                    queue.push_back(events);
+                    drop(queue);
+                    self.item_available.notify_one();
+                    self.update_gauge();
+                    return Ok(());
+                }
+            }
+
+            let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+            if remaining.is_zero()
+                || tokio::time::timeout(remaining, self.space_available.notified())
+                    .await
+                    .is_err()
+            {
+                return Err(events);
+            }
+        }
+    }
+
+    /// Waits for and removes the oldest batch from the queue.
+    ///
+    /// Returns `None` once `close` has been called and the queue has drained.
+    async fn pop(&self, closed: &std::sync::atomic::AtomicBool) -> Option<Vec<Event>> {
+        loop {
+            {
+                let mut queue = self.inner.lock().expect("in-flight queue poisoned");
+                if let Some(events) = queue.pop_front() {
+                    drop(queue);
+                    self.space_available.notify_one();
+                    self.update_gauge();
+                    return Some(events);
+                }
+                if closed.load(std::sync::atomic::Ordering::Acquire) {
+                    return None;
+                }
+            }
+            self.item_available.notified().await;
+        }
+    }
+}
+
+/// Configuration for application-layer reassembly of chunked UDP payloads.
+///
+/// When set, every datagram is expected to carry a small fixed header ahead
+/// of its payload: a big-endian `message_id: u64`, `chunk_index: u16`, and a
+/// one-byte `last_chunk` flag. The source reassembles chunks sharing a
+/// `message_id` before handing the concatenated payload to framing/decoding,
+/// allowing logical messages to span more than one datagram or exceed
+/// `max_length`.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct UdpReassemblyConfig {
+    /// How long a partially received message is kept before being discarded.
+    ///
+    /// This bounds how long a lost chunk can keep a message's buffered bytes
+    /// alive.
+    #[serde(default = "default_reassembly_timeout_secs")]
+    timeout_secs: f64,
+
+    /// The maximum number of bytes of incomplete chunked messages to buffer
+    /// per peer address.
+    ///
+    /// Chunks that would push a peer over this limit are dropped rather than
+    /// buffered, bounding memory regardless of how many messages a peer has
+    /// in flight.
+    #[serde(default = "default_max_reassembly_bytes_per_peer")]
+    max_buffered_bytes_per_peer: usize,
+}
+
+const fn default_reassembly_timeout_secs() -> f64 {
+    10.0
+}
+
+const fn default_max_reassembly_bytes_per_peer() -> usize {
+    16 * 1024 * 1024
+}
+
+/// The length, in bytes, of the fixed chunk header: `message_id` (8) +
+/// `chunk_index` (2) + `last_chunk` (1).
+const REASSEMBLY_HEADER_LEN: usize = 8 + 2 + 1;
+
+struct ChunkHeader {
+    message_id: u64,
+    chunk_index: u16,
+    last_chunk: bool,
+}
+
+fn parse_chunk_header(payload: &Bytes) -> Option<(ChunkHeader, Bytes)> {
+    if payload.len() < REASSEMBLY_HEADER_LEN {
+        return None;
+    }
+
+    let message_id = u64::from_be_bytes(payload[0..8].try_into().ok()?);
+    let chunk_index = u16::from_be_bytes(payload[8..10].try_into().ok()?);
+    let last_chunk = payload[10] != 0;
+    let body = payload.slice(REASSEMBLY_HEADER_LEN..);
+
+    Some((
+        ChunkHeader {
+            message_id,
+            chunk_index,
+            last_chunk,
+        },
+        body,
+    ))
+}
+
+/// The chunks received so far for one in-progress logical message.
+struct PartialMessage {
+    chunks: Vec<Option<Bytes>>,
+    last_chunk_index: Option<u16>,
+    buffered_bytes: usize,
+    /// Bytes of `Vec<Option<Bytes>>` slot overhead charged against the peer's cap for this
+    /// message, i.e. `chunks.len() * size_of::<Option<Bytes>>()` at the time each chunk grew it.
+    ///
+    /// Tracked separately from `buffered_bytes` because it's accounted for as soon as `chunks`
+    /// is resized to fit a `chunk_index`, before the chunk's payload (if any) arrives.
+    slot_bytes: usize,
+    last_seen: Instant,
+}
+
+impl PartialMessage {
+    fn new() -> Self {
+        Self {
+            chunks: Vec::new(),
+            last_chunk_index: None,
+            buffered_bytes: 0,
+            slot_bytes: 0,
+            last_seen: Instant::now(),
+        }
+    }
+
+    /// A message is complete only once every index in `0..=last_chunk_index` has actually been
+    /// filled in -- not just when the right *number* of chunks have arrived. A sender (or a
+    /// spoofed peer) shipping chunks past the one flagged `last_chunk` could otherwise make
+    /// `chunks.len()` line up by coincidence while real gaps remain earlier in the sequence.
+    fn is_complete(&self) -> bool {
+        match self.last_chunk_index {
+            Some(last) => {
+                let last = last as usize;
+                self.chunks.len() > last && self.chunks[..=last].iter().all(Option::is_some)
+            }
+            None => false,
+        }
+    }
+
+    /// Concatenates chunks `0..=last_chunk_index` in order. Only valid once [`Self::is_complete`]
+    /// returns `true`; any chunks received past `last_chunk_index` are ignored rather than
+    /// appended, since they aren't part of the logical message.
+    fn concat(&mut self) -> BytesMut {
+        let last = self
+            .last_chunk_index
+            .expect("concat is only called on a complete message") as usize;
+        let mut out = BytesMut::with_capacity(self.buffered_bytes);
+        for chunk in &mut self.chunks[..=last] {
+            if let Some(chunk) = chunk.take() {
+                out.extend_from_slice(&chunk);
+            }
+        }
+        out
+    }
+}
+
+/// The result of feeding one chunk into [`ReassemblyState`].
+enum ReassemblyOutcome {
+    /// All chunks of the message have arrived; here they are, concatenated in order.
+    Complete(BytesMut),
+    /// The message is still missing chunks.
+    Pending,
+    /// The chunk was dropped, either because buffering it would push the peer over
+    /// `max_buffered_bytes_per_peer`, or because it would open a new message for a peer that
+    /// already has `MAX_IN_FLIGHT_MESSAGES_PER_PEER` messages in flight.
+    Dropped,
+}
+
+#[derive(Default)]
+struct ReassemblyTable {
+    partials: HashMap<(SocketAddr, u64), PartialMessage>,
+    peer_buffered_bytes: HashMap<SocketAddr, usize>,
+    /// Count of distinct in-progress `message_id`s per peer, capped independently of
+    /// `peer_buffered_bytes` so a flood of minimal, header-only chunks (each opening a new
+    /// `message_id` with a large `chunk_index`) can't allocate unbounded `Vec<Option<Bytes>>`
+    /// slots before their byte cost is even counted.
+    peer_in_flight_messages: HashMap<SocketAddr, usize>,
+}
+
+/// The maximum number of distinct `message_id`s a single peer may have in flight at once.
+///
+/// Bounds the number of live `partials` entries (and the `PartialMessage`/`Vec` overhead that
+/// comes with each one) independently of `max_buffered_bytes_per_peer`.
+const MAX_IN_FLIGHT_MESSAGES_PER_PEER: usize = 1024;
+
+/// Shared state tracking in-progress chunked messages across every receive-loop task of a
+/// `udp()` source instance.
+struct ReassemblyState {
+    table: Mutex<ReassemblyTable>,
+    max_buffered_bytes_per_peer: usize,
+    timeout: Duration,
+}
+
+/// Removes `peer`'s entries from `peer_buffered_bytes`/`peer_in_flight_messages` once both have
+/// dropped back to zero.
+///
+/// UDP source addresses are trivially spoofed, so without this a peer that completes (or times
+/// out) every message it ever opens still leaves a permanent zero-valued entry behind -- letting
+/// an attacker grow these maps without bound just by varying the spoofed source address.
+fn prune_peer_if_idle(
+    peer_buffered_bytes: &mut HashMap<SocketAddr, usize>,
+    peer_in_flight_messages: &mut HashMap<SocketAddr, usize>,
+    peer: SocketAddr,
+) {
+    if matches!(peer_buffered_bytes.get(&peer), Some(0) | None) {
+        peer_buffered_bytes.remove(&peer);
+    }
+    if matches!(peer_in_flight_messages.get(&peer), Some(0) | None) {
+        peer_in_flight_messages.remove(&peer);
+    }
+}
+
+impl ReassemblyState {
+    fn new(config: &UdpReassemblyConfig) -> Self {
+        Self {
+            table: Mutex::new(ReassemblyTable::default()),
+            max_buffered_bytes_per_peer: config.max_buffered_bytes_per_peer,
+            timeout: Duration::from_secs_f64(config.timeout_secs),
+        }
+    }
+
+    fn ingest(&self, peer: SocketAddr, header: ChunkHeader, body: Bytes) -> ReassemblyOutcome {
+        let mut table = self.table.lock().expect("reassembly table poisoned");
+        let ReassemblyTable {
+            partials,
+            peer_buffered_bytes,
+            peer_in_flight_messages,
+        } = &mut *table;
+
+        let key = (peer, header.message_id);
+        let is_new_message = !partials.contains_key(&key);
+        if is_new_message
+            && *peer_in_flight_messages.get(&peer).unwrap_or(&0) >= MAX_IN_FLIGHT_MESSAGES_PER_PEER
+        {
+            return ReassemblyOutcome::Dropped;
+        }
+
+        // Growing `chunks` to fit `chunk_index` allocates `Option<Bytes>` slots regardless of
+        // how much payload this particular chunk carries, so that overhead has to count against
+        // the cap just like real payload bytes do -- otherwise a flood of near-empty chunks with
+        // huge indices could allocate unbounded memory while reporting as zero bytes buffered.
+        let chunk_index = header.chunk_index as usize;
+        let existing_chunk_slots = partials.get(&key).map_or(0, |partial| partial.chunks.len());
+        let wanted_chunk_slots = (chunk_index + 1).max(existing_chunk_slots);
+        let new_slot_bytes =
+            (wanted_chunk_slots - existing_chunk_slots) * std::mem::size_of::<Option<Bytes>>();
+
+        // Checked before `entry` so a dropped chunk from a new peer leaves no zero-valued entry.
+        let current_peer_bytes = *peer_buffered_bytes.get(&peer).unwrap_or(&0);
+        if current_peer_bytes + body.len() + new_slot_bytes > self.max_buffered_bytes_per_peer {
+            return ReassemblyOutcome::Dropped;
+        }
+        let peer_bytes = peer_buffered_bytes.entry(peer).or_insert(0);
+
+        let partial = partials.entry(key).or_insert_with(PartialMessage::new);
+        if is_new_message {
+            *peer_in_flight_messages.entry(peer).or_insert(0) += 1;
+        }
+
+        if partial.chunks.len() <= chunk_index {
+            partial.chunks.resize(chunk_index + 1, None);
+        }
+        partial.slot_bytes += new_slot_bytes;
+        *peer_bytes += new_slot_bytes;
+        if partial.chunks[chunk_index].is_none() {
+            partial.buffered_bytes += body.len();
+            *peer_bytes += body.len();
+            partial.chunks[chunk_index] = Some(body);
+        }
+        if header.last_chunk {
+            partial.last_chunk_index = Some(header.chunk_index);
+        }
+        partial.last_seen = Instant::now();
+
+        if partial.is_complete() {
+            let mut partial = partials.remove(&key).expect("just inserted");
+            *peer_buffered_bytes.get_mut(&peer).expect("just inserted") -=
+                partial.buffered_bytes + partial.slot_bytes;
+            if let Some(count) = peer_in_flight_messages.get_mut(&peer) {
+                *count = count.saturating_sub(1);
+            }
+            prune_peer_if_idle(peer_buffered_bytes, peer_in_flight_messages, peer);
+            ReassemblyOutcome::Complete(partial.concat())
+        } else {
+            ReassemblyOutcome::Pending
+        }
+    }
+
+    /// Immediately discards any in-progress message for `message_id` at `peer`, freeing its
+    /// buffered bytes without waiting for `sweep`'s timeout.
+    ///
+    /// Used when a chunk was itself truncated by the socket layer: reassembling it would
+    /// silently splice corrupted data into the logical message, so the whole thing is abandoned
+    /// rather than completed.
+    fn discard(&self, peer: SocketAddr, message_id: u64) {
+        let mut table = self.table.lock().expect("reassembly table poisoned");
+        let ReassemblyTable {
+            partials,
+            peer_buffered_bytes,
+            peer_in_flight_messages,
+        } = &mut *table;
+
+        if let Some(partial) = partials.remove(&(peer, message_id)) {
+            if let Some(peer_bytes) = peer_buffered_bytes.get_mut(&peer) {
+                *peer_bytes = peer_bytes.saturating_sub(partial.buffered_bytes + partial.slot_bytes);
+            }
+            if let Some(count) = peer_in_flight_messages.get_mut(&peer) {
+                *count = count.saturating_sub(1);
+            }
+            prune_peer_if_idle(peer_buffered_bytes, peer_in_flight_messages, peer);
+        }
+    }
+
+    /// Evicts messages that haven't seen a new chunk in over `timeout`, returning how many were
+    /// evicted.
+    fn sweep(&self) -> usize {
+        let mut table = self.table.lock().expect("reassembly table poisoned");
+        let ReassemblyTable {
+            partials,
+            peer_buffered_bytes,
+            peer_in_flight_messages,
+        } = &mut *table;
+
+        let timeout = self.timeout;
+        let mut evicted = 0;
+        partials.retain(|(peer, _), partial| {
+            if partial.last_seen.elapsed() < timeout {
+                return true;
+            }
+            if let Some(peer_bytes) = peer_buffered_bytes.get_mut(peer) {
+                *peer_bytes = peer_bytes.saturating_sub(partial.buffered_bytes + partial.slot_bytes);
+            }
+            if let Some(count) = peer_in_flight_messages.get_mut(peer) {
+                *count = count.saturating_sub(1);
+            }
+            prune_peer_if_idle(peer_buffered_bytes, peer_in_flight_messages, *peer);
+            evicted += 1;
+            false
+        });
+        evicted
+    }
+}
+
 /// UDP configuration for the `socket` source.
 #[configurable_component]
 #[derive(Clone, Debug)]
@@ -67,6 +574,46 @@ pub struct UdpConfig {
     /// This should not typically needed to be changed.
     receive_buffer_bytes: Option<usize>,
 
+    /// Additional addresses to listen for messages on.
+    ///
+    /// Each additional address runs the same number of `socket_workers` as
+    /// `address`, letting a single source instance fan in datagrams from
+    /// several ports at once.
+    #[serde(default)]
+    additional_addresses: Vec<SocketListenAddr>,
+
+    /// The number of independent UDP sockets to bind to `address` (and any
+    /// `additional_addresses`).
+    ///
+    /// Each worker socket is bound with `SO_REUSEPORT`, so the kernel
+    /// load-balances incoming datagrams across them. This is a throughput
+    /// win on multi-core hosts, since a single `recv_from`/decode/send loop
+    /// can become a bottleneck at high datagram rates.
+    #[serde(default = "default_socket_workers")]
+    socket_workers: NonZeroUsize,
+
+    /// The maximum number of decoded event batches that may be queued between
+    /// the socket receive loop and the task that sends events downstream.
+    ///
+    /// When this limit is reached, `overload_strategy` determines how
+    /// incoming datagrams are handled until the queue drains. Without this
+    /// protection, a backpressured downstream causes the kernel receive
+    /// buffer to fill and datagrams to be dropped invisibly.
+    #[serde(default = "default_max_in_flight_events")]
+    max_in_flight_events: usize,
+
+    /// The strategy used to shed load once `max_in_flight_events` batches are
+    /// already queued for sending.
+    #[configurable(derived)]
+    #[serde(default)]
+    overload_strategy: UdpOverloadStrategy,
+
+    /// Enables application-layer reassembly of payloads sent across multiple
+    /// chunked datagrams, so messages can exceed a single `max_length`.
+    #[configurable(derived)]
+    #[serde(default)]
+    reassembly: Option<UdpReassemblyConfig>,
+
     #[configurable(derived)]
     #[serde(default = "default_framing_message_based")]
     pub(super) framing: FramingConfig,
@@ -101,6 +648,26 @@ impl UdpConfig {
         self.address
     }
 
+    pub(super) fn additional_addresses(&self) -> &[SocketListenAddr] {
+        &self.additional_addresses
+    }
+
+    pub(super) const fn socket_workers(&self) -> NonZeroUsize {
+        self.socket_workers
+    }
+
+    pub(super) const fn max_in_flight_events(&self) -> usize {
+        self.max_in_flight_events
+    }
+
+    pub(super) const fn overload_strategy(&self) -> UdpOverloadStrategy {
+        self.overload_strategy
+    }
+
+    pub(super) fn reassembly(&self) -> Option<&UdpReassemblyConfig> {
+        self.reassembly.as_ref()
+    }
+
     pub fn from_address(address: SocketListenAddr) -> Self {
         Self {
             address,
@@ -108,6 +675,11 @@ impl UdpConfig {
             host_key: None,
             port_key: Some(String::from("port")),
             receive_buffer_bytes: None,
+            additional_addresses: Vec::new(),
+            socket_workers: default_socket_workers(),
+            max_in_flight_events: default_max_in_flight_events(),
+            overload_strategy: UdpOverloadStrategy::default(),
+            reassembly: None,
             framing: default_framing_message_based(),
             decoding: default_decoding(),
             log_namespace: None,
@@ -124,23 +696,53 @@ pub(super) fn udp(
     config: UdpConfig,
     decoder: Decoder,
     mut shutdown: ShutdownSignal,
-    mut out: SourceSender,
+    out: SourceSender,
     log_namespace: LogNamespace,
 ) -> Source {
     Box::pin(async move {
-        let listenfd = ListenFd::from_env();
-        let socket = try_bind_udp_socket(config.address, listenfd)
-            .await
-            .map_err(|error| {
-                emit!(SocketBindError {
-                    mode: SocketMode::Udp,
-                    error,
-                })
-            })?;
-
-        if let Some(receive_buffer_bytes) = config.receive_buffer_bytes {
-            if let Err(error) = udp::set_receive_buffer_size(&socket, receive_buffer_bytes) {
-                warn!(message = "Failed configuring receive buffer size on UDP socket.", %error);
+        let addresses: Vec<SocketListenAddr> = std::iter::once(config.address())
+            .chain(config.additional_addresses().iter().copied())
+            .collect();
+        let socket_workers = config.socket_workers().get();
+
+        let mut sockets = Vec::with_capacity(addresses.len() * socket_workers);
+        for address in &addresses {
+            // Binding more than one socket to the *same* address only works if each of them
+            // sets `SO_REUSEPORT` before bind, so the kernel load-balances datagrams between
+            // them. Distinct addresses never contend with each other (different ports, or a
+            // different systemd fd). `socket_workers` applies to every address alike, so with a
+            // single worker each address keeps using the existing (listenfd-aware) bind path.
+            let reuseport = socket_workers > 1;
+
+            for _ in 0..socket_workers {
+                let socket = if reuseport {
+                    bind_reuseport_udp_socket(*address).map_err(|error| {
+                        emit!(SocketBindError {
+                            mode: SocketMode::Udp,
+                            error,
+                        })
+                    })?
+                } else {
+                    let listenfd = ListenFd::from_env();
+                    try_bind_udp_socket(*address, listenfd)
+                        .await
+                        .map_err(|error| {
+                            emit!(SocketBindError {
+                                mode: SocketMode::Udp,
+                                error,
+                            })
+                        })?
+                };
+
+                if let Some(receive_buffer_bytes) = config.receive_buffer_bytes {
+                    if let Err(error) = udp::set_receive_buffer_size(&socket, receive_buffer_bytes)
+                    {
+                        warn!(message = "Failed configuring receive buffer size on UDP socket.", %error);
+                    }
+                }
+
+                info!(message = "Listening.", address = %address);
+                sockets.push(socket);
             }
         }
 
@@ -149,131 +751,485 @@ pub(super) fn udp(
             None => config.max_length,
         };
 
-        let bytes_received = register!(BytesReceived::from(Protocol::UDP));
+        // Receiving is decoupled from sending via a bounded in-flight queue so that a
+        // backpressured `out` can't stall `recv_from`, which would otherwise let the kernel
+        // receive buffer silently overflow. `overload_strategy` governs what happens once the
+        // queue is full.
+        let in_flight = InFlightQueue::new(config.max_in_flight_events());
+        let closed = Arc::new(std::sync::atomic::AtomicBool::new(false));
 
-        info!(message = "Listening.", address = %config.address);
+        let send_task = tokio::spawn({
+            let in_flight = Arc::clone(&in_flight);
+            let closed = Arc::clone(&closed);
+            let mut out = out;
+            let mut send_shutdown = shutdown.clone();
+            async move {
+                loop {
+                    let batch = tokio::select! {
+                        batch = in_flight.pop(&closed) => batch,
+                        _ = &mut send_shutdown => return,
+                    };
+                    let Some(events) = batch else { return };
+                    let count = events.len();
+                    if let Err(error) = out.send_batch(events).await {
+                        emit!(StreamClosedError { error, count });
+                        return;
+                    }
+                }
+            }
+        });
 
-        // We add 1 to the max_length in order to determine if the received data has been truncated.
-        let mut buf = BytesMut::with_capacity(max_length + 1);
-        loop {
-            buf.resize(max_length + 1, 0);
-            tokio::select! {
-                recv = socket.recv_from(&mut buf) => {
-                    let (byte_size, address) = match recv {
-                        Ok(res) => res,
-                        Err(error) => {
-                            #[cfg(windows)]
-                            if let Some(err) = error.raw_os_error() {
-                                if err == 10040 {
-                                    // 10040 is the Windows error that the Udp message has exceeded max_length
-                                    warn!(
-                                        message = "Discarding frame larger than max_length.",
-                                        max_length = max_length,
-                                        internal_log_rate_limit = true
-                                    );
+        // When configured, reassembly state is shared by every receive-loop task below (and the
+        // sweep task), since `SO_REUSEPORT` can land chunks of the same message on different
+        // worker sockets.
+        let reassembly = config.reassembly().map(ReassemblyState::new).map(Arc::new);
+
+        let sweep_task = reassembly.clone().map(|reassembly| {
+            let mut sweep_shutdown = shutdown.clone();
+            tokio::spawn(async move {
+                let mut interval = tokio::time::interval(reassembly.timeout);
+                loop {
+                    tokio::select! {
+                        _ = interval.tick() => {
+                            let evicted = reassembly.sweep();
+                            if evicted > 0 {
+                                emit!(UdpEventsDropped {
+                                    count: evicted,
+                                    reason: "reassembly timeout: incomplete chunked message discarded",
+                                });
+                            }
+                        }
+                        _ = &mut sweep_shutdown => return,
+                    }
+                }
+            })
+        });
+
+        // Each socket gets its own receive loop (and its own `BytesReceived` handle, since
+        // internal events are keyed per task/registration) but all of them feed the single
+        // shared in-flight queue (and reassembly state, if any) above.
+        let mut receive_tasks = Vec::with_capacity(sockets.len());
+        for socket in sockets {
+            let decoder = decoder.clone();
+            let config = config.clone();
+            let in_flight = Arc::clone(&in_flight);
+            let reassembly = reassembly.clone();
+            let mut shutdown = shutdown.clone();
+            let bytes_received = register!(BytesReceived::from(Protocol::UDP));
+            receive_tasks.push(tokio::spawn(async move {
+                udp_receive_loop(
+                    &socket,
+                    &decoder,
+                    &config,
+                    max_length,
+                    &bytes_received,
+                    &in_flight,
+                    reassembly.as_deref(),
+                    &mut shutdown,
+                    log_namespace,
+                )
+                .await
+            }));
+        }
+
+        let mut result = Ok(());
+        for task in receive_tasks {
+            match task.await {
+                Ok(Ok(())) => {}
+                Ok(Err(())) | Err(_) => result = Err(()),
+            }
+        }
+
+        closed.store(true, std::sync::atomic::Ordering::Release);
+        in_flight.item_available.notify_waiters();
+        let _ = send_task.await;
+        if let Some(sweep_task) = sweep_task {
+            sweep_task.abort();
+        }
+
+        result
+    })
+}
+
+/// Binds a UDP socket to `address` with `SO_REUSEPORT` set before bind, so several of these
+/// sockets can share one address and let the kernel load-balance datagrams across them.
+fn bind_reuseport_udp_socket(address: SocketListenAddr) -> std::io::Result<tokio::net::UdpSocket> {
+    let addr = match address {
+        SocketListenAddr::SocketAddr(addr) => addr,
+        SocketListenAddr::SystemdFd(_) => {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "socket_workers greater than 1 is not supported with systemd socket activation",
+            ))
+        }
+    };
+
+    let domain = if addr.is_ipv4() {
+        Domain::IPV4
+    } else {
+        Domain::IPV6
+    };
+    let socket = Socket::new(domain, Type::DGRAM, Some(SocketProtocol::UDP))?;
+    socket.set_reuse_address(true)?;
+    #[cfg(unix)]
+    socket.set_reuse_port(true)?;
+    socket.set_nonblocking(true)?;
+    socket.bind(&addr.into())?;
+
+    tokio::net::UdpSocket::from_std(socket.into())
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn udp_receive_loop(
+    socket: &tokio::net::UdpSocket,
+    decoder: &Decoder,
+    config: &UdpConfig,
+    max_length: usize,
+    bytes_received: &Registered<BytesReceived>,
+    in_flight: &Arc<InFlightQueue>,
+    reassembly: Option<&ReassemblyState>,
+    shutdown: &mut ShutdownSignal,
+    log_namespace: LogNamespace,
+) -> Result<(), ()> {
+    // We add 1 to the max_length in order to determine if the received data has been truncated.
+    let mut buf = BytesMut::with_capacity(max_length + 1);
+    loop {
+        buf.resize(max_length + 1, 0);
+        tokio::select! {
+            recv = socket.recv_from(&mut buf) => {
+                let (byte_size, address) = match recv {
+                    Ok(res) => res,
+                    Err(error) => {
+                        #[cfg(windows)]
+                        if let Some(err) = error.raw_os_error() {
+                            if err == 10040 {
+                                // 10040 is the Windows error that the Udp message has exceeded max_length
+                                warn!(
+                                    message = "Discarding frame larger than max_length.",
+                                    max_length = max_length,
+                                    internal_log_rate_limit = true
+                                );
+                                continue;
+                            }
+                        }
+
+                        return Err(emit!(SocketReceiveError {
+                            mode: SocketMode::Udp,
+                            error
+                        }));
+                   }
+                };
+
+                bytes_received.emit(ByteSize(byte_size));
+
+                let payload = buf.split_to(byte_size).freeze();
+                let truncated = byte_size == max_length + 1;
+
+                let (frame_bytes, truncated): (Bytes, bool) = match reassembly {
+                    None => (payload, truncated),
+                    Some(reassembly) => match parse_chunk_header(&payload) {
+                        Some((header, body)) => {
+                            if truncated {
+                                // A truncated chunk is missing its tail, so splicing it into the
+                                // reassembled message would silently corrupt it. Discard the
+                                // whole in-progress message rather than complete it wrong.
+                                warn!(
+                                    message = "Discarding frame larger than max_length.",
+                                    max_length = max_length,
+                                    internal_log_rate_limit = true
+                                );
+                                reassembly.discard(address, header.message_id);
+                                continue;
+                            }
+
+                            match reassembly.ingest(address, header, body) {
+                                ReassemblyOutcome::Complete(bytes) => (bytes.freeze(), false),
+                                ReassemblyOutcome::Pending => continue,
+                                ReassemblyOutcome::Dropped => {
+                                    emit!(UdpEventsDropped {
+                                        count: 1,
+                                        reason: "reassembly buffer full for peer",
+                                    });
                                     continue;
                                 }
                             }
+                        }
+                        None => {
+                            warn!(
+                                message = "Discarding datagram missing chunk reassembly header.",
+                                internal_log_rate_limit = true
+                            );
+                            continue;
+                        }
+                    },
+                };
 
-                            return Err(emit!(SocketReceiveError {
-                                mode: SocketMode::Udp,
-                                error
-                            }));
-                       }
-                    };
+                let mut stream = FramedRead::new(frame_bytes.as_ref(), decoder.clone()).peekable();
 
-                    bytes_received.emit(ByteSize(byte_size));
+                while let Some(result) = stream.next().await {
+                    let last = Pin::new(&mut stream).peek().await.is_none();
+                    match result {
+                        Ok((mut events, _byte_size)) => {
+                            if last && truncated {
+                                // The last event in this payload was truncated, so we want to drop it.
+                                let _ = events.pop();
+                                warn!(
+                                    message = "Discarding frame larger than max_length.",
+                                    max_length = max_length,
+                                    internal_log_rate_limit = true
+                                );
+                            }
 
-                    let payload = buf.split_to(byte_size);
-                    let truncated = byte_size == max_length + 1;
+                            if events.is_empty() {
+                                continue;
+                            }
 
-                    let mut stream = FramedRead::new(payload.as_ref(), decoder.clone()).peekable();
+                            let count = events.len();
+                            emit!(SocketEventsReceived {
+                                mode: SocketMode::Udp,
+                                byte_size: events.size_of(),
+                                count,
+                            });
+
+                            let now = Utc::now();
 
-                    while let Some(result) = stream.next().await {
-                        let last = Pin::new(&mut stream).peek().await.is_none();
-                        match result {
-                            Ok((mut events, _byte_size)) => {
-                                if last && truncated {
-                                    // The last event in this payload was truncated, so we want to drop it.
-                                    let _ = events.pop();
-                                    warn!(
-                                        message = "Discarding frame larger than max_length.",
-                                        max_length = max_length,
-                                        internal_log_rate_limit = true
+                            for event in &mut events {
+                                if let Event::Log(ref mut log) = event {
+                                    log_namespace.insert_standard_vector_source_metadata(
+                                        log,
+                                        SocketConfig::NAME,
+                                        now,
                                     );
-                                }
 
-                                if events.is_empty() {
-                                    continue;
-                                }
+                                    let host_key_path = config.host_key.as_ref().map_or_else(
+                                        || [BorrowedSegment::from(log_schema().host_key())],
+                                        |key| [BorrowedSegment::from(key)],
+                                    );
 
-                                let count = events.len();
-                                emit!(SocketEventsReceived {
-                                    mode: SocketMode::Udp,
-                                    byte_size: events.size_of(),
-                                    count,
-                                });
+                                    log_namespace.insert_source_metadata(
+                                        SocketConfig::NAME,
+                                        log,
+                                        Some(LegacyKey::InsertIfEmpty(&host_key_path)),
+                                        path!("host"),
+                                        address.ip().to_string()
+                                    );
 
-                                let now = Utc::now();
-
-                                for event in &mut events {
-                                    if let Event::Log(ref mut log) = event {
-                                        log_namespace.insert_standard_vector_source_metadata(
-                                            log,
-                                            SocketConfig::NAME,
-                                            now,
-                                        );
-
-                                        let host_key_path = config.host_key.as_ref().map_or_else(
-                                            || [BorrowedSegment::from(log_schema().host_key())],
-                                            |key| [BorrowedSegment::from(key)],
-                                        );
-
-                                        log_namespace.insert_source_metadata(
-                                            SocketConfig::NAME,
-                                            log,
-                                            Some(LegacyKey::InsertIfEmpty(&host_key_path)),
-                                            path!("host"),
-                                            address.ip().to_string()
-                                        );
-
-                                        let port_key_path = config.port_key.as_ref().map_or_else(
-                                            || [BorrowedSegment::from("port")],
-                                            |key| [BorrowedSegment::from(key)],
-                                        );
-
-                                        log_namespace.insert_source_metadata(
-                                            SocketConfig::NAME,
-                                            log,
-                                            Some(LegacyKey::InsertIfEmpty(&port_key_path)),
-                                            path!("port"),
-                                            address.port()
-                                        );
-                                    }
+                                    let port_key_path = config.port_key.as_ref().map_or_else(
+                                        || [BorrowedSegment::from("port")],
+                                        |key| [BorrowedSegment::from(key)],
+                                    );
+
+                                    log_namespace.insert_source_metadata(
+                                        SocketConfig::NAME,
+                                        log,
+                                        Some(LegacyKey::InsertIfEmpty(&port_key_path)),
+                                        path!("port"),
+                                        address.port()
+                                    );
                                 }
+                            }
 
-                                tokio::select!{
-                                    result = out.send_batch(events) => {
-                                        if let Err(error) = result {
-                                            emit!(StreamClosedError { error, count });
-                                            return Ok(())
+                            let dropped = match config.overload_strategy() {
+                                UdpOverloadStrategy::DropNewest => {
+                                    in_flight.push_drop_newest(events)
+                                }
+                                UdpOverloadStrategy::DropOldest => {
+                                    in_flight.push_drop_oldest(events)
+                                }
+                                UdpOverloadStrategy::Block { timeout_secs } => {
+                                    tokio::select! {
+                                        result = in_flight.push_block(events, Duration::from_secs_f64(timeout_secs)) => {
+                                            result.err()
                                         }
+                                        _ = &mut *shutdown => return Ok(()),
                                     }
-                                    _ = &mut shutdown => return Ok(()),
                                 }
+                            };
+
+                            if let Some(dropped) = dropped {
+                                emit!(UdpEventsDropped {
+                                    count: dropped.len(),
+                                    reason: "UDP source in-flight queue is overloaded",
+                                });
                             }
-                            Err(error) => {
-                                // Error is logged by `crate::codecs::Decoder`, no
-                                // further handling is needed here.
-                                if !error.can_continue() {
-                                    break;
-                                }
+                        }
+                        Err(error) => {
+                            // Error is logged by `crate::codecs::Decoder`, no
+                            // further handling is needed here.
+                            if !error.can_continue() {
+                                break;
                             }
                         }
                     }
                 }
-                _ = &mut shutdown => return Ok(()),
             }
+            _ = &mut *shutdown => return Ok(()),
         }
-    })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event() -> Event {
+        Event::from("test")
+    }
+
+    #[test]
+    fn in_flight_queue_drop_newest_drops_the_incoming_batch_when_full() {
+        let queue = InFlightQueue::new(2);
+        assert!(queue.push_drop_newest(vec![event()]).is_none());
+        assert!(queue.push_drop_newest(vec![event()]).is_none());
+        assert_eq!(queue.len(), 2);
+
+        let third = vec![event(), event()];
+        let dropped = queue
+            .push_drop_newest(third)
+            .expect("queue is full, incoming batch should be dropped");
+        assert_eq!(dropped.len(), 2);
+        assert_eq!(queue.len(), 2);
+    }
+
+    #[test]
+    fn in_flight_queue_drop_oldest_evicts_the_front_of_the_queue_when_full() {
+        let queue = InFlightQueue::new(2);
+        queue.push_drop_oldest(vec![event()]);
+        queue.push_drop_oldest(vec![event(), event()]);
+        assert_eq!(queue.len(), 2);
+
+        let incoming = vec![event(), event(), event()];
+        let dropped = queue
+            .push_drop_oldest(incoming)
+            .expect("queue is full, oldest batch should be evicted");
+        assert_eq!(dropped.len(), 1);
+        assert_eq!(queue.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn in_flight_queue_push_block_returns_the_batch_once_the_timeout_elapses() {
+        let queue = InFlightQueue::new(1);
+        queue
+            .push_block(vec![event()], Duration::from_millis(50))
+            .await
+            .expect("queue has room for the first batch");
+
+        let incoming = vec![event(), event()];
+        let result = queue
+            .push_block(incoming, Duration::from_millis(20))
+            .await;
+        let returned = result.expect_err("queue stays full, push_block should time out");
+        assert_eq!(returned.len(), 2);
+        assert_eq!(queue.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn in_flight_queue_push_block_succeeds_once_room_frees_up() {
+        let closed = std::sync::atomic::AtomicBool::new(false);
+        let queue = InFlightQueue::new(1);
+        queue
+            .push_block(vec![event()], Duration::from_millis(50))
+            .await
+            .expect("queue has room for the first batch");
+
+        assert!(queue.pop(&closed).await.is_some());
+        queue
+            .push_block(vec![event()], Duration::from_millis(50))
+            .await
+            .expect("room freed up by the pop above");
+        assert_eq!(queue.len(), 1);
+    }
+
+    fn peer(port: u16) -> SocketAddr {
+        SocketAddr::from(([127, 0, 0, 1], port))
+    }
+
+    fn header(message_id: u64, chunk_index: u16, last_chunk: bool) -> ChunkHeader {
+        ChunkHeader {
+            message_id,
+            chunk_index,
+            last_chunk,
+        }
+    }
+
+    fn reassembly_state() -> ReassemblyState {
+        ReassemblyState::new(&UdpReassemblyConfig {
+            timeout_secs: 10.0,
+            max_buffered_bytes_per_peer: 1024,
+        })
+    }
+
+    fn outcome_name(outcome: &ReassemblyOutcome) -> &'static str {
+        match outcome {
+            ReassemblyOutcome::Complete(_) => "Complete",
+            ReassemblyOutcome::Pending => "Pending",
+            ReassemblyOutcome::Dropped => "Dropped",
+        }
+    }
+
+    #[test]
+    fn reassembly_ingest_completes_once_every_chunk_index_is_filled() {
+        let state = reassembly_state();
+        let peer = peer(1);
+
+        assert!(matches!(
+            state.ingest(peer, header(1, 0, false), Bytes::from_static(b"foo")),
+            ReassemblyOutcome::Pending
+        ));
+        match state.ingest(peer, header(1, 1, true), Bytes::from_static(b"bar")) {
+            ReassemblyOutcome::Complete(bytes) => assert_eq!(&bytes[..], b"foobar"),
+            other => panic!("expected Complete, got {}", outcome_name(&other)),
+        }
+    }
+
+    #[test]
+    fn reassembly_ingest_does_not_complete_while_an_earlier_index_is_missing() {
+        let state = reassembly_state();
+        let peer = peer(2);
+
+        // Only the chunk flagged `last_chunk` arrives; index 0 is still missing, so this must
+        // not be reported as complete just because `chunks.len()` happens to match.
+        assert!(matches!(
+            state.ingest(peer, header(1, 1, true), Bytes::from_static(b"bar")),
+            ReassemblyOutcome::Pending
+        ));
+
+        match state.ingest(peer, header(1, 0, false), Bytes::from_static(b"foo")) {
+            ReassemblyOutcome::Complete(bytes) => assert_eq!(&bytes[..], b"foobar"),
+            other => panic!("expected Complete, got {}", outcome_name(&other)),
+        }
+    }
+
+    #[test]
+    fn reassembly_discard_prunes_the_peer_once_it_has_no_bytes_or_messages_left() {
+        let state = reassembly_state();
+        let peer = peer(3);
+
+        state.ingest(peer, header(1, 0, false), Bytes::from_static(b"foo"));
+        state.discard(peer, 1);
+
+        let table = state.table.lock().expect("reassembly table poisoned");
+        assert!(!table.partials.contains_key(&(peer, 1)));
+        assert!(!table.peer_buffered_bytes.contains_key(&peer));
+        assert!(!table.peer_in_flight_messages.contains_key(&peer));
+    }
+
+    #[test]
+    fn reassembly_sweep_evicts_stale_messages_and_prunes_the_peer() {
+        let state = ReassemblyState::new(&UdpReassemblyConfig {
+            timeout_secs: 0.0,
+            max_buffered_bytes_per_peer: 1024,
+        });
+        let peer = peer(4);
+
+        state.ingest(peer, header(1, 0, false), Bytes::from_static(b"foo"));
+        std::thread::sleep(Duration::from_millis(10));
+
+        assert_eq!(state.sweep(), 1);
+
+        let table = state.table.lock().expect("reassembly table poisoned");
+        assert!(table.partials.is_empty());
+        assert!(!table.peer_buffered_bytes.contains_key(&peer));
+        assert!(!table.peer_in_flight_messages.contains_key(&peer));
+    }
 }